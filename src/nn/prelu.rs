@@ -0,0 +1,33 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::{Merge, NoneTape, Tape, Tensor},
+    tensor_ops::{
+        ops::BinaryKernel,
+        prelu::{prelu, PReLUKernelOp},
+    },
+};
+
+/// Applies a learnable Parametric ReLU: `PReLU(x) = max(0, x) + alpha * min(0, x)`,
+/// with one `alpha` per element of `S`, delegating to the real [prelu] kernel.
+///
+/// Unlike the rest of `nn`, this targets the shape-generic `Tensor<S, E, D, T>`
+/// API directly - the same one [prelu] is written against - rather than the
+/// const-generic `Tensor0D`/.../`Tensor4D` family [Linear]/[ReLU]/[Residual]
+/// still use, since that's the only place a `prelu` op actually exists in this
+/// crate. It can't be dropped into an old-API `FF`/`Residual` stack as a
+/// result, and `alpha` must match the input's shape exactly - [prelu] has no
+/// broadcasting of its own, so there's no per-channel shorthand here the way
+/// the old, now-removed `Tensor1D<C>` form had.
+#[derive(Clone, Debug)]
+pub struct PReLU<S: Shape, E: Dtype, D: BinaryKernel<PReLUKernelOp, E>> {
+    pub alpha: Tensor<S, E, D, NoneTape>,
+}
+
+impl<S: Shape, E: Dtype, D: BinaryKernel<PReLUKernelOp, E>> PReLU<S, E, D> {
+    pub fn forward<T>(&self, input: Tensor<S, E, D, T>) -> Tensor<S, E, D, T>
+    where
+        T: Tape<E, D> + Merge<NoneTape>,
+    {
+        prelu(input, self.alpha.clone())
+    }
+}
@@ -0,0 +1,116 @@
+use crate::gradients::{CanUpdateWithGradients, GradientProvider, UnusedTensors};
+use crate::prelude::*;
+use crate::tensor_ops::{dequantize, quantize};
+use std::io::{Read, Seek, Write};
+use zip::{result::ZipResult, ZipArchive, ZipWriter};
+
+/// **Requires Nightly** A [Linear] layer whose weight is stored as symmetric
+/// per-output-column int8 values plus an `f32` scale instead of `f32`,
+/// cutting weight memory roughly 4x for inference. See [Linear::quantize].
+///
+/// Generics
+/// - `I`: The number of input units.
+/// - `O`: The number of output units.
+/// TODO: Doctests
+#[derive(Clone, Debug)]
+pub struct QuantizedLinear<const I: usize, const O: usize> {
+    weight: [[i8; I]; O],
+    scale: [f32; O],
+    bias: Tensor1D<O>,
+}
+
+impl<const I: usize, const O: usize> Linear<I, O> {
+    /// Quantizes `self.weight` to symmetric per-column int8, keeping `bias`
+    /// in full precision. See [quantize].
+    pub fn quantize(self) -> QuantizedLinear<I, O> {
+        let (weight, scale) = quantize(&self.weight);
+        QuantizedLinear {
+            weight,
+            scale,
+            bias: self.bias,
+        }
+    }
+}
+
+impl<const I: usize, const O: usize> QuantizedLinear<I, O> {
+    /// Dequantizes `self.weight` back to a plain [Linear], e.g. to resume
+    /// training after inference with the compressed weights.
+    pub fn dequantize(&self) -> Linear<I, O> {
+        Linear {
+            weight: dequantize(&self.weight, &self.scale),
+            bias: self.bias.duplicate(),
+        }
+    }
+}
+
+impl<const I: usize, const O: usize> CanUpdateWithGradients for QuantizedLinear<I, O> {
+    fn update<G: GradientProvider>(&mut self, grads: &mut G, unused: &mut UnusedTensors) {
+        // the int8 weight is frozen; only the f32 bias can still be trained.
+        self.bias.update(grads, unused);
+    }
+}
+
+impl<const I: usize, const O: usize, Src, Out> Module<Src> for QuantizedLinear<I, O>
+where
+    Linear<I, O>: Module<Src, Output = Out>,
+{
+    type Output = Out;
+
+    /// Dequantizes the weight on the fly, then runs the ordinary [Linear]
+    /// forward pass so the public [Module] interface is unchanged.
+    fn forward(&self, input: Src) -> Self::Output {
+        self.dequantize().forward(input)
+    }
+}
+
+impl<const I: usize, const O: usize, T> ModuleMut<T> for QuantizedLinear<I, O>
+where
+    Self: Module<T>,
+{
+    type Output = <Self as Module<T>>::Output;
+
+    fn forward_mut(&mut self, t: T) -> Self::Output {
+        self.forward(t)
+    }
+}
+
+impl<const I: usize, const O: usize> SaveToNpz for QuantizedLinear<I, O> {
+    fn write<W: Write + Seek>(&self, pre: &str, w: &mut ZipWriter<W>) -> ZipResult<()> {
+        npz_fwrite(w, format!("{pre}weight.npy"), &self.weight)?;
+        npz_fwrite(w, format!("{pre}scale.npy"), &self.scale)?;
+        npz_fwrite(w, format!("{pre}bias.npy"), self.bias.data())?;
+        Ok(())
+    }
+}
+
+impl<const I: usize, const O: usize> LoadFromNpz for QuantizedLinear<I, O> {
+    fn read<R: Read + Seek>(&mut self, pre: &str, r: &mut ZipArchive<R>) -> Result<(), NpzError> {
+        npz_fread(r, format!("{pre}weight.npy"), &mut self.weight)?;
+        npz_fread(r, format!("{pre}scale.npy"), &mut self.scale)?;
+        npz_fread(r, format!("{pre}bias.npy"), self.bias.mut_data())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quantize_approximates_the_unquantized_output() {
+        let linear: Linear<4, 2> = Linear {
+            weight: Tensor2D::new([[0.1, -0.2, 0.3, -0.4], [0.5, -0.05, 0.15, -0.25]]),
+            bias: Tensor1D::new([0.01, -0.02]),
+        };
+        let x: Tensor1D<4> = Tensor1D::new([1.0, 2.0, 3.0, 4.0]);
+
+        let y = linear.clone().forward(x.duplicate());
+        let quantized = linear.quantize();
+        let y_quantized = quantized.forward(x);
+
+        // int8 quantization only has to be close, not bit-identical.
+        for (a, b) in y.data().iter().zip(y_quantized.data().iter()) {
+            assert!((a - b).abs() < 0.05);
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use crate::gradients::{CanUpdateWithGradients, GradientProvider, UnusedTensors};
+use crate::prelude::*;
+use std::io::{Read, Seek, Write};
+use zip::{result::ZipResult, ZipArchive, ZipWriter};
+
+/// **Requires Nightly** A transformer decoder.
+///
+/// Generics
+/// - `MODEL_DIM`: The size of query/key/value tensors. Given to [MultiHeadAttention].
+/// - `NUM_HEADS`: The number of heads in [MultiHeadAttention].
+/// - `FF_DIM`: The size of the hidden layer in
+///   the feedforward network in [TransformerDecoderBlock].
+/// - `NUM_LAYERS`: The number of [TransformerDecoderBlock] to use.
+/// TODO: Doctests
+pub type TransformerDecoder<
+    const MODEL_DIM: usize,
+    const NUM_HEADS: usize,
+    const FF_DIM: usize,
+    const NUM_LAYERS: usize,
+> = Repeated<TransformerDecoderBlock<MODEL_DIM, NUM_HEADS, FF_DIM>, NUM_LAYERS>;
+
+/// **Requires Nightly** A single transformer decoder block, as in
+/// [TransformerEncoderBlock] plus a masked self-attention sublayer and an
+/// encoder-decoder cross-attention sublayer.
+///
+/// Generics
+/// - `MODEL_DIM`: The size of query/key/value tensors. Given to [MultiHeadAttention].
+/// - `NUM_HEADS`: The number of heads in [MultiHeadAttention].
+/// - `FF_DIM`: The size of the hidden layer in the feedforward network.
+///
+/// **Pytorch equivalent**:
+/// ```python
+/// decoder = torch.nn.TransformerDecoderLayer(
+///    EMBED_DIM, NUM_HEADS, dim_feedforward=FF_DIM, batch_first=True, dropout=0.0
+/// )
+/// ```
+/// TODO: Doctests
+#[derive(Clone, Debug, Default)]
+pub struct TransformerDecoderBlock<
+    const MODEL_DIM: usize,
+    const NUM_HEADS: usize,
+    const FF_DIM: usize,
+> {
+    self_attn: MultiHeadAttention<MODEL_DIM, NUM_HEADS>,
+    norm1: LayerNorm1D<MODEL_DIM>,
+    mh_attn: MultiHeadAttention<MODEL_DIM, NUM_HEADS>,
+    norm2: LayerNorm1D<MODEL_DIM>,
+    ff: FF<MODEL_DIM, FF_DIM>,
+    norm3: LayerNorm1D<MODEL_DIM>,
+}
+
+type FF<const M: usize, const F: usize> = Residual<(Linear<M, F>, ReLU, Linear<F, M>)>;
+
+impl<const M: usize, const H: usize, const F: usize> ResetParams
+    for TransformerDecoderBlock<M, H, F>
+{
+    fn reset_params<R: rand::Rng>(&mut self, rng: &mut R) {
+        self.self_attn.reset_params(rng);
+        self.norm1.reset_params(rng);
+        self.mh_attn.reset_params(rng);
+        self.norm2.reset_params(rng);
+        self.ff.reset_params(rng);
+        self.norm3.reset_params(rng);
+    }
+}
+
+impl<const M: usize, const H: usize, const F: usize> CanUpdateWithGradients
+    for TransformerDecoderBlock<M, H, F>
+{
+    fn update<G: GradientProvider>(&mut self, grads: &mut G, unused: &mut UnusedTensors) {
+        self.self_attn.update(grads, unused);
+        self.norm1.update(grads, unused);
+        self.mh_attn.update(grads, unused);
+        self.norm2.update(grads, unused);
+        self.ff.update(grads, unused);
+        self.norm3.update(grads, unused);
+    }
+}
+
+impl<const M: usize, const H: usize, const F: usize, const SEQ_LEN: usize, Tgt, Mem>
+    Module<(Tgt, Mem)> for TransformerDecoderBlock<M, H, F>
+where
+    Tgt: Tensor<Dtype = f32, Array = [[f32; M]; SEQ_LEN]>,
+    Mem: Tensor<Dtype = f32, NoTape = Tgt::NoTape> + Clone,
+    MultiHeadAttention<M, H>: Module<
+            (Tgt, Tgt::NoTape, Tgt::NoTape, Tensor2D<SEQ_LEN, SEQ_LEN>),
+            Output = Tgt,
+        > + Module<(Tgt, Mem::NoTape, Mem::NoTape), Output = Tgt>,
+    LayerNorm1D<M>: Module<Tgt, Output = Tgt>,
+    FF<M, F>: Module<Tgt, Output = Tgt>,
+{
+    type Output = Tgt;
+
+    /// `tgt` is the (shifted-right) decoder input, `mem` is the encoder's
+    /// output. `tgt`'s self-attention is causally masked so position `i`
+    /// cannot look at any position after it; see [causal_mask].
+    fn forward(&self, (tgt, mem): (Tgt, Mem)) -> Self::Output {
+        let (tgt, tape) = tgt.split_tape();
+        let mask = causal_mask::<SEQ_LEN>();
+        let x = self.self_attn.forward((
+            tgt.duplicate().put_tape(tape),
+            tgt.duplicate(),
+            tgt.duplicate(),
+            mask,
+        ));
+        let x = add(x, &tgt);
+        let x = self.norm1.forward(x);
+
+        let (x, tape) = x.split_tape();
+        let y = self.mh_attn.forward((
+            x.duplicate().put_tape(tape),
+            mem.duplicate(),
+            mem.duplicate(),
+        ));
+        let y = add(y, &x);
+        let y = self.norm2.forward(y);
+
+        let z = self.ff.forward(y);
+        self.norm3.forward(z)
+    }
+}
+
+impl<const M: usize, const H: usize, const F: usize, T> ModuleMut<T>
+    for TransformerDecoderBlock<M, H, F>
+where
+    Self: Module<T>,
+{
+    type Output = <Self as Module<T>>::Output;
+
+    fn forward_mut(&mut self, t: T) -> Self::Output {
+        self.forward(t)
+    }
+}
+
+impl<const M: usize, const H: usize, const F: usize> SaveToNpz
+    for TransformerDecoderBlock<M, H, F>
+{
+    fn write<W: Write + Seek>(&self, pre: &str, w: &mut ZipWriter<W>) -> ZipResult<()> {
+        self.self_attn.write(&format!("{pre}self_attn."), w)?;
+        self.norm1.write(&format!("{pre}norm1."), w)?;
+        self.mh_attn.write(&format!("{pre}multihead_attn."), w)?;
+        self.norm2.write(&format!("{pre}norm2."), w)?;
+        self.ff.0 .0.write(&format!("{pre}linear1."), w)?;
+        self.ff.0 .2.write(&format!("{pre}linear2."), w)?;
+        self.norm3.write(&format!("{pre}norm3."), w)?;
+        Ok(())
+    }
+}
+
+impl<const M: usize, const H: usize, const F: usize> LoadFromNpz
+    for TransformerDecoderBlock<M, H, F>
+{
+    fn read<R: Read + Seek>(&mut self, pre: &str, r: &mut ZipArchive<R>) -> Result<(), NpzError> {
+        self.self_attn.read(&format!("{pre}self_attn."), r)?;
+        self.norm1.read(&format!("{pre}norm1."), r)?;
+        self.mh_attn.read(&format!("{pre}multihead_attn."), r)?;
+        self.norm2.read(&format!("{pre}norm2."), r)?;
+        self.ff.0 .0.read(&format!("{pre}linear1."), r)?;
+        self.ff.0 .2.read(&format!("{pre}linear2."), r)?;
+        self.norm3.read(&format!("{pre}norm3."), r)?;
+        Ok(())
+    }
+}
+
+/// Builds an additive `[SEQ_LEN, SEQ_LEN]` causal mask: `0` where `j <= i`
+/// and `-inf` where `j > i`, so that once added to attention scores (before
+/// softmax), position `i` cannot attend to any position after it.
+pub fn causal_mask<const SEQ_LEN: usize>() -> Tensor2D<SEQ_LEN, SEQ_LEN> {
+    let mut mask = [[0.0; SEQ_LEN]; SEQ_LEN];
+    for (i, row) in mask.iter_mut().enumerate() {
+        for v in row.iter_mut().skip(i + 1) {
+            *v = f32::NEG_INFINITY;
+        }
+    }
+    Tensor2D::new(mask)
+}
+
+/// Builds an additive `[SEQ_LEN]` mask that adds `-inf` to every position
+/// where `is_padding[i]` is `true`, so padding tokens receive ~0 attention
+/// weight from every query once added to the attention scores.
+pub fn key_padding_mask<const SEQ_LEN: usize>(is_padding: [bool; SEQ_LEN]) -> Tensor1D<SEQ_LEN> {
+    let mut mask = [0.0; SEQ_LEN];
+    for (v, pad) in mask.iter_mut().zip(is_padding) {
+        if pad {
+            *v = f32::NEG_INFINITY;
+        }
+    }
+    Tensor1D::new(mask)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_causal_mask() {
+        let mask = causal_mask::<3>();
+        assert_eq!(
+            mask.data(),
+            &[
+                [0.0, f32::NEG_INFINITY, f32::NEG_INFINITY],
+                [0.0, 0.0, f32::NEG_INFINITY],
+                [0.0, 0.0, 0.0],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_key_padding_mask() {
+        let mask = key_padding_mask([false, true, false, true]);
+        assert_eq!(
+            mask.data(),
+            &[0.0, f32::NEG_INFINITY, 0.0, f32::NEG_INFINITY]
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use crate::prelude::*;
+
+/// The masked counterpart of [MultiHeadAttention]'s unmasked
+/// `Module<(Q, Q::NoTape, Q::NoTape)>` impl: adds an additive mask to the
+/// scaled `q @ k^T` attention scores before the softmax, so a query can be
+/// kept from attending to some set of key positions (see
+/// [causal_mask](super::decoder::causal_mask), and
+/// [key_padding_mask](super::decoder::key_padding_mask), whose `[SEQ_LEN]`
+/// output has to be broadcast to one row per query before it fits the
+/// `[SEQ_LEN, SEQ_LEN]` mask taken here) without changing
+/// anything else about the attention computation. [TransformerDecoderBlock]'s
+/// self-attention sublayer needs exactly this - the unmasked form alone
+/// can't express "don't look ahead".
+impl<const M: usize, const H: usize, const SEQ_LEN: usize, Tgt>
+    Module<(Tgt, Tgt::NoTape, Tgt::NoTape, Tensor2D<SEQ_LEN, SEQ_LEN>)>
+    for MultiHeadAttention<M, H>
+where
+    Tgt: Tensor<Dtype = f32, Array = [[f32; M]; SEQ_LEN]>,
+    Linear<M, M>: Module<Tgt, Output = Tgt> + Module<Tgt::NoTape, Output = Tgt::NoTape>,
+{
+    type Output = Tgt;
+
+    fn forward(
+        &self,
+        (q, k, v, mask): (Tgt, Tgt::NoTape, Tgt::NoTape, Tensor2D<SEQ_LEN, SEQ_LEN>),
+    ) -> Self::Output {
+        let q = self.w_q.forward(q);
+        let k = self.w_k.forward(k);
+        let v = self.w_v.forward(v);
+
+        let scale = 1.0 / ((M / H) as f32).sqrt();
+        let scores = add(matmul_transpose(q, &k).scale(scale), &mask);
+        let weights = softmax(scores);
+        self.w_o.forward(matmul(weights, &v))
+    }
+}
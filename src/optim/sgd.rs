@@ -1,26 +1,55 @@
 use super::traits::Optimizer;
+use crate::gradients::{CanUpdateWithGradients, GradientProvider, Gradients, UnusedTensors};
 use crate::nn::traits::Module;
 use crate::tensor::traits::*;
 use std::ops::{Deref, DerefMut};
 
+/// Momentum variants supported by [Sgd]. Both accumulate a velocity buffer
+/// `v <- momentum * v + g`, keyed per-parameter the same way [Gradients] is;
+/// they differ in how that velocity is folded back into the update.
+#[derive(Debug, Clone, Copy)]
+pub enum Momentum {
+    /// `theta <- theta - lr * v`
+    Classic(f32),
+    /// `theta <- theta - lr * (g + momentum * v)`
+    Nesterov(f32),
+}
+
+/// Weight decay variants supported by [Sgd].
+#[derive(Debug, Clone, Copy)]
+pub enum WeightDecay {
+    /// Adds `wd * theta` to the gradient before the momentum update.
+    L2(f32),
+    /// Subtracts `lr * wd * theta` directly from the parameter, decoupled
+    /// from the gradient and momentum, as in AdamW.
+    Decoupled(f32),
+}
+
 #[derive(Debug)]
 pub struct SgdConfig {
     pub lr: f32,
+    pub momentum: Option<Momentum>,
+    pub weight_decay: Option<WeightDecay>,
 }
 
 impl Default for SgdConfig {
     fn default() -> Self {
-        Self { lr: 1e-2 }
+        Self {
+            lr: 1e-2,
+            momentum: None,
+            weight_decay: None,
+        }
     }
 }
 
 #[derive(Default, Debug)]
-pub struct Sgd<M: Module> {
+pub struct Sgd<M> {
     pub cfg: SgdConfig,
     pub module: M,
+    velocity: Gradients,
 }
 
-impl<M: Module> Deref for Sgd<M> {
+impl<M> Deref for Sgd<M> {
     type Target = M;
 
     fn deref(&self) -> &Self::Target {
@@ -28,16 +57,145 @@ impl<M: Module> Deref for Sgd<M> {
     }
 }
 
-impl<M: Module> DerefMut for Sgd<M> {
+impl<M> DerefMut for Sgd<M> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.module
     }
 }
 
-impl<M: Module> Optimizer<M> for Sgd<M> {
+impl<M: CanUpdateWithGradients> Optimizer<M> for Sgd<M> {
     fn step<T: Tensor>(&mut self, loss: &mut T) {
-        let mut tape = loss.backward().unwrap();
-        tape.scale(self.cfg.lr);
-        self.update(&tape);
+        let gradients = loss.backward().unwrap();
+        let mut provider = SgdGradientProvider {
+            cfg: &self.cfg,
+            gradients,
+            velocity: &mut self.velocity,
+        };
+        let mut unused = UnusedTensors::default();
+        self.module.update(&mut provider, &mut unused);
+    }
+}
+
+/// Sits between the raw [Gradients] produced by `backward()` and each
+/// parameter's [CanUpdateWithGradients::update], applying weight decay,
+/// momentum, and the learning rate before handing back the final update.
+struct SgdGradientProvider<'cfg, 'v> {
+    cfg: &'cfg SgdConfig,
+    gradients: Gradients,
+    velocity: &'v mut Gradients,
+}
+
+impl<'cfg, 'v> GradientProvider for SgdGradientProvider<'cfg, 'v> {
+    fn gradient<P>(&mut self, p: &P) -> Option<Box<P::Array>>
+    where
+        P: HasUniqueId + HasArrayType<Dtype = f32> + HasDevice,
+    {
+        let mut g = self.gradients.remove(p)?;
+
+        if let Some(WeightDecay::L2(wd)) = self.cfg.weight_decay {
+            P::Device::add_scaled(g.as_mut(), p.data(), wd);
+        }
+
+        let mut update = match self.cfg.momentum {
+            Some(Momentum::Classic(mu)) => {
+                let v = self.velocity.mut_gradient(p);
+                P::Device::scale(v, mu);
+                P::Device::add_assign(v, g.as_ref());
+                Box::new(v.clone())
+            }
+            Some(Momentum::Nesterov(mu)) => {
+                let v = self.velocity.mut_gradient(p);
+                P::Device::scale(v, mu);
+                P::Device::add_assign(v, g.as_ref());
+                let mut update = g;
+                P::Device::add_scaled(update.as_mut(), v, mu);
+                update
+            }
+            None => g,
+        };
+
+        P::Device::scale(update.as_mut(), self.cfg.lr);
+
+        if let Some(WeightDecay::Decoupled(wd)) = self.cfg.weight_decay {
+            P::Device::add_scaled(update.as_mut(), p.data(), self.cfg.lr * wd);
+        }
+
+        Some(update)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Runs [SgdGradientProvider::gradient] for `p` against a single
+    /// hand-picked incoming gradient, returning the update it produces.
+    fn step(cfg: &SgdConfig, velocity: &mut Gradients, p: &Tensor1D<1>, grad: f32) -> f32 {
+        let mut gradients = Gradients::default();
+        *gradients.mut_gradient(p) = [grad];
+        let mut provider = SgdGradientProvider {
+            cfg,
+            gradients,
+            velocity,
+        };
+        provider.gradient(p).unwrap()[0]
+    }
+
+    #[test]
+    fn test_classic_momentum_accumulates_velocity() {
+        let cfg = SgdConfig {
+            lr: 0.1,
+            momentum: Some(Momentum::Classic(0.9)),
+            weight_decay: None,
+        };
+        let p: Tensor1D<1> = Tensor1D::new([0.0]);
+        let mut velocity = Gradients::default();
+
+        // v0 = 0.9 * 0 + 1.0 = 1.0; update = lr * v0 = 0.1
+        assert_eq!(step(&cfg, &mut velocity, &p, 1.0), 0.1);
+        // v1 = 0.9 * 1.0 + 1.0 = 1.9; update = lr * v1 = 0.19
+        assert_eq!(step(&cfg, &mut velocity, &p, 1.0), 0.19);
+    }
+
+    #[test]
+    fn test_nesterov_momentum_looks_ahead_of_velocity() {
+        let cfg = SgdConfig {
+            lr: 0.1,
+            momentum: Some(Momentum::Nesterov(0.9)),
+            weight_decay: None,
+        };
+        let p: Tensor1D<1> = Tensor1D::new([0.0]);
+        let mut velocity = Gradients::default();
+
+        // v0 = 0.9 * 0 + 1.0 = 1.0; update = lr * (g + mu * v0) = 0.1 * (1.0 + 0.9) = 0.19
+        assert_eq!(step(&cfg, &mut velocity, &p, 1.0), 0.19);
+    }
+
+    #[test]
+    fn test_l2_weight_decay_adds_to_the_gradient() {
+        let cfg = SgdConfig {
+            lr: 0.1,
+            momentum: None,
+            weight_decay: Some(WeightDecay::L2(0.5)),
+        };
+        let p: Tensor1D<1> = Tensor1D::new([2.0]);
+        let mut velocity = Gradients::default();
+
+        // g' = g + wd * theta = 1.0 + 0.5 * 2.0 = 2.0; update = lr * g' = 0.2
+        assert_eq!(step(&cfg, &mut velocity, &p, 1.0), 0.2);
+    }
+
+    #[test]
+    fn test_decoupled_weight_decay_is_independent_of_the_gradient() {
+        let cfg = SgdConfig {
+            lr: 0.1,
+            momentum: None,
+            weight_decay: Some(WeightDecay::Decoupled(0.5)),
+        };
+        let p: Tensor1D<1> = Tensor1D::new([2.0]);
+        let mut velocity = Gradients::default();
+
+        // update = lr * g + lr * wd * theta = 0.1 * 1.0 + 0.1 * 0.5 * 2.0 = 0.2
+        assert_eq!(step(&cfg, &mut velocity, &p, 1.0), 0.2);
     }
 }
\ No newline at end of file
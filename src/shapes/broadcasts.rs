@@ -18,6 +18,60 @@ impl ReduceShape<Axis<0>> for () {
 }
 impl<Src: Shape, Dst: Shape + ReduceShapeTo<Src, Ax>, Ax> BroadcastShapeTo<Dst, Ax> for Src {}
 
+/// Infers a common [Shape] two operands can each be broadcast to by stacking
+/// their dims, so e.g. a `Rank1<3>` and a `Rank1<7>` combine into a
+/// `Rank2<3, 7>`. It only names the shape and axes; it does not broadcast
+/// any tensor itself.
+///
+/// Unlike [BroadcastShapeTo], where the caller names `Dst` explicitly,
+/// here `Self::Dst`/`Self::AxLhs`/`Self::AxRhs` are computed from the two
+/// input shapes alone: `Self`'s dims come ahead of `Rhs`'s, so `Self::Dst`
+/// has rank `Self::NUM_DIMS + Rhs::NUM_DIMS`. `Self::AxLhs` is the trailing
+/// `Rhs::NUM_DIMS` axes of `Dst` (what `Self` is missing and must be
+/// broadcast/summed over), `Self::AxRhs` is the leading `Self::NUM_DIMS`
+/// axes (what `Rhs` is missing).
+///
+/// This is not numpy-style broadcasting: the two operands always contribute
+/// disjoint axes to `Dst`, dims are never aligned from the trailing end, and
+/// a dim of size `1` is never expanded to match the other operand. Both
+/// operands must have rank at least 1, and their ranks must add up to at
+/// most 6.
+pub trait CoBroadcastShapeTo<Rhs>: Sized {
+    type Dst: Shape;
+    type AxLhs: Axes;
+    type AxRhs: Axes;
+}
+
+macro_rules! co_broadcast_to {
+    ([$($lhs:ident)*] [$($rhs:ident)*]) => {
+        impl<$($lhs: Dim,)* $($rhs: Dim,)*> CoBroadcastShapeTo<($($rhs,)*)> for ($($lhs,)*)
+        where
+            ($($lhs,)*): BroadcastBottomDimsTo<($($lhs,)* $($rhs,)*)>,
+            ($($rhs,)*): BroadcastTopDimsTo<($($lhs,)* $($rhs,)*)>,
+        {
+            type Dst = ($($lhs,)* $($rhs,)*);
+            type AxLhs = <($($lhs,)*) as BroadcastBottomDimsTo<($($lhs,)* $($rhs,)*)>>::Ax;
+            type AxRhs = <($($rhs,)*) as BroadcastTopDimsTo<($($lhs,)* $($rhs,)*)>>::Ax;
+        }
+    };
+}
+
+co_broadcast_to!([A] [B]);
+co_broadcast_to!([A] [B C]);
+co_broadcast_to!([A] [B C D]);
+co_broadcast_to!([A] [B C D E]);
+co_broadcast_to!([A] [B C D E F]);
+co_broadcast_to!([A B] [C]);
+co_broadcast_to!([A B] [C D]);
+co_broadcast_to!([A B] [C D E]);
+co_broadcast_to!([A B] [C D E F]);
+co_broadcast_to!([A B C] [D]);
+co_broadcast_to!([A B C] [D E]);
+co_broadcast_to!([A B C] [D E F]);
+co_broadcast_to!([A B C D] [E]);
+co_broadcast_to!([A B C D] [E F]);
+co_broadcast_to!([A B C D E] [F]);
+
 macro_rules! broadcast_to_array {
     ($SrcNum:tt, (), $DstNum:tt, ($($DstDims:tt),*), $Axes:ty) => {
         impl ReduceShapeTo<(), $Axes> for [usize; $DstNum] {}
@@ -269,4 +323,36 @@ mod tests {
             );
         assert_eq!(dst_strides, [0, 1, 0]);
     }
+
+    #[test]
+    fn test_co_broadcast_infers_dst_and_axes() {
+        // Rank1<3> and Rank1<7> co-broadcast into Rank2<3, 7>: each operand
+        // is missing exactly the axis the other one owns. Unlike
+        // BroadcastShapeTo, nothing here names Dst/AxLhs/AxRhs up front -
+        // this only compiles if CoBroadcastShapeTo infers exactly these.
+        fn assert_co_broadcasts<Lhs, Rhs>()
+        where
+            Lhs: CoBroadcastShapeTo<Rhs, Dst = (Const<3>, Const<7>), AxLhs = Axis<1>, AxRhs = Axis<0>>,
+        {
+        }
+        assert_co_broadcasts::<(Const<3>,), (Const<7>,)>();
+    }
+
+    #[test]
+    fn test_co_broadcast_drives_broadcast_strides() {
+        // The inferred Dst/AxLhs/AxRhs should be usable anywhere a
+        // hand-picked Dst/Ax would be - here, to broadcast each operand's
+        // strides into their common shape.
+        type Dst = <(usize,) as CoBroadcastShapeTo<(usize,)>>::Dst;
+        type AxLhs = <(usize,) as CoBroadcastShapeTo<(usize,)>>::AxLhs;
+        type AxRhs = <(usize,) as CoBroadcastShapeTo<(usize,)>>::AxRhs;
+
+        let lhs = (3,);
+        let lhs_strides = BroadcastStridesTo::<Dst, AxLhs>::broadcast_strides(&lhs, lhs.strides());
+        assert_eq!(lhs_strides, [1, 0]);
+
+        let rhs = (7,);
+        let rhs_strides = BroadcastStridesTo::<Dst, AxRhs>::broadcast_strides(&rhs, rhs.strides());
+        assert_eq!(rhs_strides, [0, 1]);
+    }
 }
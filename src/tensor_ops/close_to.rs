@@ -0,0 +1,138 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::{DeviceStorage, Tensor},
+};
+
+/// How strict a closeness check should be. The exact `(atol, rtol)` pair is
+/// dtype-dependent since `f16` simply can't resolve as many digits as `f32`
+/// or `f64` - see [HasApproxTolerance::tolerance].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Approximation {
+    /// No tolerance at all: the values must match exactly.
+    Exact,
+    /// The tolerance two values produced by equivalent computations (e.g.
+    /// one in dfdx, one in Pytorch) should agree to.
+    Close,
+    /// A looser tolerance for results that went through more numerically
+    /// sensitive paths (e.g. chained reductions, mixed-precision kernels).
+    Approximate,
+}
+
+/// A dtype-specific `(atol, rtol)` pair, combined using the standard mixed
+/// criterion `|a - b| <= atol + rtol * |b|`.
+pub trait HasApproxTolerance: Dtype {
+    fn tolerance(approx: Approximation) -> (Self, Self);
+}
+
+macro_rules! wide_float_tolerance {
+    ($Ty:ty) => {
+        impl HasApproxTolerance for $Ty {
+            fn tolerance(approx: Approximation) -> (Self, Self) {
+                match approx {
+                    Approximation::Exact => (0.0, 0.0),
+                    Approximation::Close => (1e-7, 1e-7),
+                    Approximation::Approximate => (1e-4, 5e-4),
+                }
+            }
+        }
+    };
+}
+wide_float_tolerance!(f32);
+wide_float_tolerance!(f64);
+
+#[cfg(feature = "f16")]
+impl HasApproxTolerance for half::f16 {
+    fn tolerance(approx: Approximation) -> (Self, Self) {
+        use half::f16;
+        match approx {
+            Approximation::Exact => (f16::from_f32(0.0), f16::from_f32(0.0)),
+            Approximation::Close => (f16::from_f32(1e-3), f16::from_f32(1e-3)),
+            Approximation::Approximate => (f16::from_f32(1e-3), f16::from_f32(5e-3)),
+        }
+    }
+}
+
+/// Adds dtype-aware closeness checks on top of plain equality, so tests
+/// don't have to hand-pick a tolerance per dtype.
+pub trait CloseTo {
+    /// Returns `true` if every element of `self` and `rhs` satisfies
+    /// `|a - b| <= atol + rtol * |b|` for the `(atol, rtol)` of `approx`.
+    fn is_close_with(&self, rhs: &Self, approx: Approximation) -> bool;
+
+    /// Same as [CloseTo::is_close_with], but with [Approximation::Close].
+    fn is_close(&self, rhs: &Self) -> bool {
+        self.is_close_with(rhs, Approximation::Close)
+    }
+
+    /// Panics with a useful message if `!self.is_close_with(rhs, approx)`.
+    fn assert_close_with(&self, rhs: &Self, approx: Approximation)
+    where
+        Self: std::fmt::Debug,
+    {
+        assert!(
+            self.is_close_with(rhs, approx),
+            "lhs != rhs\nlhs={self:?}\nrhs={rhs:?}\napprox={approx:?}",
+        );
+    }
+}
+
+impl<E: Dtype + HasApproxTolerance> CloseTo for E {
+    fn is_close_with(&self, rhs: &Self, approx: Approximation) -> bool {
+        let (atol, rtol) = E::tolerance(approx);
+        (*self - *rhs).abs() <= atol + rtol * rhs.abs()
+    }
+}
+
+impl<T: CloseTo, const N: usize> CloseTo for [T; N] {
+    fn is_close_with(&self, rhs: &Self, approx: Approximation) -> bool {
+        self.iter()
+            .zip(rhs.iter())
+            .all(|(a, b)| a.is_close_with(b, approx))
+    }
+}
+
+impl<S: Shape, E: Dtype + HasApproxTolerance, D: DeviceStorage + crate::tensor::AsArray<S, E>, T>
+    Tensor<S, E, D, T>
+where
+    S::Array: CloseTo,
+{
+    /// See [CloseTo::is_close_with]. Compares the tensors' underlying data,
+    /// ignoring the tape. Works for any rank - [S::Array] is recursed into
+    /// element-by-element down to the scalar [Dtype], not flattened to a
+    /// slice first, so this handles nested arrays (rank 2+) the same as
+    /// rank 0/1.
+    pub fn is_close_with(&self, rhs: &Self, approx: Approximation) -> bool {
+        self.array().is_close_with(&rhs.array(), approx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_requires_bit_equality() {
+        assert!([1.0f32, 2.0].is_close_with(&[1.0, 2.0], Approximation::Exact));
+        assert!(![1.0f32, 2.0].is_close_with(&[1.0, 2.000001], Approximation::Exact));
+    }
+
+    #[test]
+    fn test_close_tolerates_f32_rounding() {
+        assert!([1.0f32].is_close_with(&[1.0000001], Approximation::Close));
+        assert!(![1.0f32].is_close_with(&[1.01], Approximation::Close));
+    }
+
+    #[test]
+    fn test_approximate_is_looser_than_close() {
+        assert!(![1.0f32].is_close_with(&[1.0003], Approximation::Close));
+        assert!([1.0f32].is_close_with(&[1.0003], Approximation::Approximate));
+    }
+
+    #[test]
+    fn test_nested_arrays_recurse_element_by_element() {
+        let a = [[1.0f32, 2.0], [3.0, 4.0]];
+        let b = [[1.0000001, 2.0], [3.0, 4.0000001]];
+        assert!(a.is_close_with(&b, Approximation::Close));
+        assert!(![[1.0f32, 2.0], [3.0, 4.0]].is_close_with(&[[1.0, 2.0], [3.0, 5.0]], Approximation::Close));
+    }
+}
@@ -0,0 +1,289 @@
+use crate::prelude::*;
+
+/// Returns the index of the maximum element of `t`, treating it as a flat
+/// buffer of [Tensor::Array] elements.
+///
+/// Unlike [mean], this is not a tape operation: there is no sensible
+/// gradient for "which index was largest", so the result is a plain index,
+/// not a [Tensor0D].
+pub fn argmax<T: Tensor<Dtype = f32>>(t: &T) -> usize {
+    argmax_of(t.data().as_ref())
+}
+
+/// Returns the index of the minimum element of `t`. See [argmax].
+pub fn argmin<T: Tensor<Dtype = f32>>(t: &T) -> usize {
+    argmin_of(t.data().as_ref())
+}
+
+/// Shared by every per-axis reduction below: the index of the largest
+/// element of a flat slice, picking the first on ties.
+fn argmax_of(xs: &[f32]) -> usize {
+    let mut best = 0;
+    let mut best_val = f32::NEG_INFINITY;
+    for (i, &v) in xs.iter().enumerate() {
+        if v > best_val {
+            best_val = v;
+            best = i;
+        }
+    }
+    best
+}
+
+/// Shared by every per-axis reduction below: the index of the smallest
+/// element of a flat slice, picking the first on ties.
+fn argmin_of(xs: &[f32]) -> usize {
+    let mut best = 0;
+    let mut best_val = f32::INFINITY;
+    for (i, &v) in xs.iter().enumerate() {
+        if v < best_val {
+            best_val = v;
+            best = i;
+        }
+    }
+    best
+}
+
+macro_rules! tensor_impl {
+    ($typename:ident, [$($Vs:tt),*]) => {
+impl<$(const $Vs: usize, )* H: TapeHolder> $typename<$($Vs, )* H> {
+    /// Calls [argmax()] on `self`.
+    pub fn argmax(&self) -> usize {
+        argmax(self)
+    }
+
+    /// Calls [argmin()] on `self`.
+    pub fn argmin(&self) -> usize {
+        argmin(self)
+    }
+}
+    };
+}
+
+impl<H: TapeHolder> Tensor0D<H> {
+    /// A [Tensor0D] only has one element, so both reductions trivially
+    /// return index `0`; unlike [Tensor1D], this can't go through
+    /// [argmax]/[argmin], which expect a flat `&[f32]` and a 0D tensor's
+    /// backing array is a bare `f32`, not `[f32; 1]`.
+    pub fn argmax(&self) -> usize {
+        0
+    }
+
+    /// See [Tensor0D::argmax].
+    pub fn argmin(&self) -> usize {
+        0
+    }
+}
+
+tensor_impl!(Tensor1D, [M]);
+
+impl<const M: usize, const N: usize, H: TapeHolder> Tensor2D<M, N, H> {
+    /// Returns, for each of the `M` rows, the index of its largest element -
+    /// i.e. reduces the last (and by default) axis, removing it from the
+    /// shape. Useful for classification readouts over a `[batch, classes]`
+    /// tensor.
+    pub fn argmax(&self) -> [usize; M] {
+        (*self.data()).map(|row| argmax_of(&row))
+    }
+
+    /// Same as [Tensor2D::argmax], but reduces axis `0` instead of the last
+    /// axis.
+    pub fn argmax_axis0(&self) -> [usize; N] {
+        let data = *self.data();
+        std::array::from_fn(|j| argmax_of(&data.map(|row| row[j])))
+    }
+
+    /// Returns, for each of the `M` rows, the index of its smallest element.
+    /// See [Tensor2D::argmax].
+    pub fn argmin(&self) -> [usize; M] {
+        (*self.data()).map(|row| argmin_of(&row))
+    }
+
+    /// Same as [Tensor2D::argmin], but reduces axis `0` instead of the last
+    /// axis.
+    pub fn argmin_axis0(&self) -> [usize; N] {
+        let data = *self.data();
+        std::array::from_fn(|j| argmin_of(&data.map(|row| row[j])))
+    }
+}
+
+impl<const M: usize, const N: usize, const O: usize, H: TapeHolder> Tensor3D<M, N, O, H> {
+    /// Reduces the last axis (length `O`), removing it from the shape.
+    /// Useful for greedy decoding over a `[batch, seq, vocab]` tensor.
+    pub fn argmax(&self) -> [[usize; N]; M] {
+        (*self.data()).map(|plane| plane.map(|row| argmax_of(&row)))
+    }
+
+    /// Same as [Tensor3D::argmax], but reduces axis `0` instead of the last
+    /// axis.
+    pub fn argmax_axis0(&self) -> [[usize; O]; N] {
+        let data = *self.data();
+        std::array::from_fn(|j| std::array::from_fn(|k| argmax_of(&data.map(|plane| plane[j][k]))))
+    }
+
+    /// Same as [Tensor3D::argmax], but reduces axis `1` instead of the last
+    /// axis.
+    pub fn argmax_axis1(&self) -> [[usize; O]; M] {
+        (*self.data()).map(|plane| std::array::from_fn(|k| argmax_of(&plane.map(|row| row[k]))))
+    }
+
+    /// Reduces the last axis (length `O`), removing it from the shape.
+    /// See [Tensor3D::argmax].
+    pub fn argmin(&self) -> [[usize; N]; M] {
+        (*self.data()).map(|plane| plane.map(|row| argmin_of(&row)))
+    }
+
+    /// Same as [Tensor3D::argmin], but reduces axis `0` instead of the last
+    /// axis.
+    pub fn argmin_axis0(&self) -> [[usize; O]; N] {
+        let data = *self.data();
+        std::array::from_fn(|j| std::array::from_fn(|k| argmin_of(&data.map(|plane| plane[j][k]))))
+    }
+
+    /// Same as [Tensor3D::argmin], but reduces axis `1` instead of the last
+    /// axis.
+    pub fn argmin_axis1(&self) -> [[usize; O]; M] {
+        (*self.data()).map(|plane| std::array::from_fn(|k| argmin_of(&plane.map(|row| row[k]))))
+    }
+}
+
+impl<const M: usize, const N: usize, const O: usize, const P: usize, H: TapeHolder>
+    Tensor4D<M, N, O, P, H>
+{
+    /// Reduces the last axis (length `P`), removing it from the shape.
+    pub fn argmax(&self) -> [[[usize; O]; N]; M] {
+        (*self.data()).map(|block| block.map(|plane| plane.map(|row| argmax_of(&row))))
+    }
+
+    /// Same as [Tensor4D::argmax], but reduces axis `0` instead of the last
+    /// axis.
+    pub fn argmax_axis0(&self) -> [[[usize; P]; O]; N] {
+        let data = *self.data();
+        std::array::from_fn(|i| {
+            std::array::from_fn(|j| {
+                std::array::from_fn(|k| argmax_of(&data.map(|block| block[i][j][k])))
+            })
+        })
+    }
+
+    /// Same as [Tensor4D::argmax], but reduces axis `1` instead of the last
+    /// axis.
+    pub fn argmax_axis1(&self) -> [[[usize; P]; O]; M] {
+        (*self.data()).map(|block| {
+            std::array::from_fn(|j| {
+                std::array::from_fn(|k| argmax_of(&block.map(|plane| plane[j][k])))
+            })
+        })
+    }
+
+    /// Same as [Tensor4D::argmax], but reduces axis `2` instead of the last
+    /// axis.
+    pub fn argmax_axis2(&self) -> [[[usize; P]; N]; M] {
+        (*self.data()).map(|block| {
+            block.map(|plane| std::array::from_fn(|k| argmax_of(&plane.map(|row| row[k]))))
+        })
+    }
+
+    /// Reduces the last axis (length `P`), removing it from the shape.
+    /// See [Tensor4D::argmax].
+    pub fn argmin(&self) -> [[[usize; O]; N]; M] {
+        (*self.data()).map(|block| block.map(|plane| plane.map(|row| argmin_of(&row))))
+    }
+
+    /// Same as [Tensor4D::argmin], but reduces axis `0` instead of the last
+    /// axis.
+    pub fn argmin_axis0(&self) -> [[[usize; P]; O]; N] {
+        let data = *self.data();
+        std::array::from_fn(|i| {
+            std::array::from_fn(|j| {
+                std::array::from_fn(|k| argmin_of(&data.map(|block| block[i][j][k])))
+            })
+        })
+    }
+
+    /// Same as [Tensor4D::argmin], but reduces axis `1` instead of the last
+    /// axis.
+    pub fn argmin_axis1(&self) -> [[[usize; P]; O]; M] {
+        (*self.data()).map(|block| {
+            std::array::from_fn(|j| {
+                std::array::from_fn(|k| argmin_of(&block.map(|plane| plane[j][k])))
+            })
+        })
+    }
+
+    /// Same as [Tensor4D::argmin], but reduces axis `2` instead of the last
+    /// axis.
+    pub fn argmin_axis2(&self) -> [[[usize; P]; N]; M] {
+        (*self.data()).map(|block| {
+            block.map(|plane| std::array::from_fn(|k| argmin_of(&plane.map(|row| row[k]))))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_argmax_argmin_0d() {
+        let t: Tensor0D = Tensor0D::new(3.0);
+        assert_eq!(t.argmax(), 0);
+        assert_eq!(t.argmin(), 0);
+    }
+
+    #[test]
+    fn test_argmax_1d() {
+        let t: Tensor1D<4> = Tensor1D::new([1.0, 3.0, -2.0, 0.5]);
+        assert_eq!(t.argmax(), 1);
+    }
+
+    #[test]
+    fn test_argmin_1d() {
+        let t: Tensor1D<4> = Tensor1D::new([1.0, 3.0, -2.0, 0.5]);
+        assert_eq!(t.argmin(), 2);
+    }
+
+    #[test]
+    fn test_argmax_ties_pick_first() {
+        let t: Tensor1D<3> = Tensor1D::new([1.0, 1.0, 0.0]);
+        assert_eq!(t.argmax(), 0);
+    }
+
+    #[test]
+    fn test_argmax_2d_reduces_last_axis() {
+        let t: Tensor2D<2, 3> = Tensor2D::new([[1.0, 3.0, -2.0], [0.5, -1.0, 2.0]]);
+        assert_eq!(t.argmax(), [1, 2]);
+        assert_eq!(t.argmin(), [2, 1]);
+    }
+
+    #[test]
+    fn test_argmax_2d_axis0() {
+        let t: Tensor2D<2, 3> = Tensor2D::new([[1.0, 3.0, -2.0], [0.5, -1.0, 2.0]]);
+        assert_eq!(t.argmax_axis0(), [0, 0, 1]);
+        assert_eq!(t.argmin_axis0(), [1, 1, 0]);
+    }
+
+    #[test]
+    fn test_argmax_3d_reduces_last_axis() {
+        let t: Tensor3D<2, 2, 2> = Tensor3D::new([
+            [[1.0, 3.0], [0.0, -1.0]],
+            [[-2.0, 2.0], [5.0, 4.0]],
+        ]);
+        assert_eq!(t.argmax(), [[1, 0], [1, 0]]);
+        assert_eq!(t.argmin(), [[0, 1], [0, 1]]);
+    }
+
+    #[test]
+    fn test_argmax_4d_axis_siblings() {
+        let t: Tensor4D<2, 1, 2, 2> = Tensor4D::new([
+            [[[1.0, 3.0], [0.0, -1.0]]],
+            [[[-2.0, 2.0], [5.0, 4.0]]],
+        ]);
+        assert_eq!(t.argmax(), [[[1, 0]], [[1, 0]]]);
+        assert_eq!(t.argmin(), [[[0, 1]], [[0, 1]]]);
+        assert_eq!(t.argmax_axis0(), [[[0, 0], [1, 1]]]);
+        assert_eq!(t.argmin_axis0(), [[[1, 1], [0, 0]]]);
+        assert_eq!(t.argmax_axis1(), [[[0, 0], [0, 0]], [[0, 0], [0, 0]]]);
+        assert_eq!(t.argmax_axis2(), [[[0, 0]], [[1, 1]]]);
+        assert_eq!(t.argmin_axis2(), [[[1, 1]], [[0, 0]]]);
+    }
+}
@@ -3,6 +3,9 @@ mod cpu_kernel;
 #[cfg(feature = "cuda")]
 mod cuda_kernel;
 
+#[cfg(feature = "tch")]
+mod tch_kernel;
+
 use super::ops::*;
 use crate::{
     shapes::*,
@@ -0,0 +1,14 @@
+use super::LeakyReLUKernelOp as Op;
+use crate::tensor_ops::tch_kernels::{tch_unary, Tch};
+
+fn forward(op: &Op<f32>, t: &tch::Tensor) -> tch::Tensor {
+    t.leaky_relu_with(op.alpha as f64)
+}
+
+fn derivative(op: &Op<f32>, t: &tch::Tensor) -> tch::Tensor {
+    // 1 where x >= 0, alpha where x < 0
+    let is_pos = t.ge(0.0).to_kind(tch::Kind::Float);
+    &is_pos + (1.0 - &is_pos) * (op.alpha as f64)
+}
+
+tch_unary!(Op<f32>, f32, forward, derivative);
@@ -0,0 +1,17 @@
+use super::PReLUKernelOp as Op;
+use crate::tensor_ops::tch_kernels::{tch_binary, Tch};
+
+fn forward(_op: &Op, x: &tch::Tensor, alpha: &tch::Tensor) -> tch::Tensor {
+    x.prelu(alpha)
+}
+
+fn dfdx(_op: &Op, x: &tch::Tensor, alpha: &tch::Tensor) -> tch::Tensor {
+    let is_pos = x.ge(0.0).to_kind(tch::Kind::Float);
+    &is_pos + (1.0 - &is_pos) * alpha
+}
+
+fn dfdy(_op: &Op, x: &tch::Tensor, _alpha: &tch::Tensor) -> tch::Tensor {
+    x.clamp_max(0.0)
+}
+
+tch_binary!(Op, f32, forward, dfdx, dfdy);
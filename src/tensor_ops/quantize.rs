@@ -0,0 +1,58 @@
+use crate::prelude::*;
+
+/// Symmetric per-output-column int8 quantization of a weight matrix, as used
+/// by [crate::nn::QuantizedLinear]. For each output column `c`:
+/// `scale_c = max_i |W[i,c]| / 127`, `q[i,c] = round(W[i,c] / scale_c)`
+/// clamped to `[-127, 127]`.
+///
+/// This is not a tape operation: quantization is a one-time compression step
+/// applied to already-trained weights, not something differentiated through.
+pub fn quantize<const I: usize, const O: usize>(t: &Tensor2D<O, I>) -> ([[i8; I]; O], [f32; O]) {
+    let mut q = [[0i8; I]; O];
+    let mut scale = [0f32; O];
+    for c in 0..O {
+        let max_abs = t.data()[c].iter().fold(0f32, |acc, &x| acc.max(x.abs()));
+        scale[c] = (max_abs / 127.0).max(f32::EPSILON);
+        for i in 0..I {
+            let v = (t.data()[c][i] / scale[c]).round().clamp(-127.0, 127.0);
+            q[c][i] = v as i8;
+        }
+    }
+    (q, scale)
+}
+
+/// Inverse of [quantize]: reconstructs an approximate `f32` weight matrix
+/// from int8 weights and their per-column scales.
+pub fn dequantize<const I: usize, const O: usize>(
+    q: &[[i8; I]; O],
+    scale: &[f32; O],
+) -> Tensor2D<O, I> {
+    let mut out = [[0f32; I]; O];
+    for c in 0..O {
+        for i in 0..I {
+            out[c][i] = q[c][i] as f32 * scale[c];
+        }
+    }
+    Tensor2D::new(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_close;
+
+    #[test]
+    fn test_quantize_dequantize_roundtrip() {
+        let w = Tensor2D::<2, 3>::new([[0.1, -0.2, 0.3], [-0.4, 0.5, -0.05]]);
+        let (q, scale) = quantize(&w);
+        assert_eq!(q[0].iter().map(|v| v.unsigned_abs()).max().unwrap(), 127);
+        let w2 = dequantize(&q, &scale);
+        // round-tripping through int8 only recovers the original up to the
+        // per-column quantization step size.
+        for c in 0..2 {
+            for i in 0..3 {
+                assert!((w.data()[c][i] - w2.data()[c][i]).abs() <= scale[c]);
+            }
+        }
+    }
+}
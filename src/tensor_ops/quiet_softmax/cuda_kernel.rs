@@ -0,0 +1,23 @@
+use super::QuietSoftmaxKernelOp as Binary;
+use crate::tensor_ops::cuda_kernels::cuda_binary;
+
+unsafe impl cudarc::driver::DeviceRepr for Binary {}
+
+const BINARY_PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/quiet_softmax.ptx"));
+
+cuda_binary!(
+    Binary,
+    f32,
+    BINARY_PTX,
+    "quiet_softmax_fwd_f32",
+    "quiet_softmax_bwd_lhs_f32",
+    "quiet_softmax_bwd_rhs_f32"
+);
+cuda_binary!(
+    Binary,
+    f64,
+    BINARY_PTX,
+    "quiet_softmax_fwd_f64",
+    "quiet_softmax_bwd_lhs_f64",
+    "quiet_softmax_bwd_rhs_f64"
+);
@@ -0,0 +1,105 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::*;
+use super::{BroadcastTo, Device};
+use crate::{
+    shapes::{Axes, ReduceShape, Shape},
+    tensor::{Merge, Tape, Tensor},
+};
+
+/// The shifted-exponential half of [quiet_softmax]'s forward pass: given a
+/// logit `x` and its (broadcast) row max `m`, computes `exp(x - m)` in a
+/// single kernel instead of a separate `sub` then `exp`, the same way the
+/// `prelu` op fuses its own elementwise formula into one kernel.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct QuietSoftmaxKernelOp;
+
+/// Also known as "softmax-off-by-one", or `softmax1`. Computes a softmax
+/// with an extra implicit logit of `0` appended to the reduced axis, so the
+/// output of a row need not sum to `1` - a row can instead put all of its
+/// weight on the virtual zero logit and effectively attend to nothing.
+///
+/// For an input `x` along the reduced axis, letting `m = max(0, max_j x_j)`:
+/// `p_i = exp(x_i - m) / (exp(-m) + sum_j exp(x_j - m))`
+///
+/// This is identical to [super::softmax] except for the extra `exp(-m)` term
+/// in the denominator. That term adds no gradient of its own: `p_i` equals
+/// `exp(x_i) / (1 + sum_j exp(x_j))`, so the shift by `m` cancels out.
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t: Tensor<Rank1<5>, f32, _> = dev.tensor([-1.0, 0.0, 1.0, 2.0, 3.0]);
+/// let _ = t.quiet_softmax();
+/// ```
+pub fn quiet_softmax<Ax: Axes, S: Shape + ReduceShape<Ax>, E: Dtype, D: Device<E>, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.quiet_softmax::<Ax>()
+}
+
+impl<S: Shape, E: Dtype, D: Device<E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [quiet_softmax]
+    pub fn quiet_softmax<Ax: Axes>(self) -> Self
+    where
+        S: ReduceShape<Ax>,
+    {
+        self.try_quiet_softmax::<Ax>().unwrap()
+    }
+
+    /// Fallible version of [Tensor::quiet_softmax]
+    pub fn try_quiet_softmax<Ax: Axes>(self) -> Result<Self, D::Err>
+    where
+        S: ReduceShape<Ax>,
+        D: BinaryKernel<QuietSoftmaxKernelOp, E>,
+        T: Merge<T>,
+    {
+        let shape = self.shape;
+        let m_reduced: Tensor<S::Reduced, E, D, T> = self
+            .retaped::<T>()
+            .try_max::<_, Ax>()?
+            .try_clamp(E::zero(), E::infinity())?;
+        // one `exp(-m)` per reduced row, added to that row's sum exactly once
+        let exp_neg_m = m_reduced.retaped::<T>().try_negate()?.try_exp()?;
+        let m = m_reduced.try_broadcast_like(&shape)?;
+        let num: Self = try_binary_op(QuietSoftmaxKernelOp, self, m)?;
+        let den = num
+            .retaped::<T>()
+            .try_sum::<_, Ax>()?
+            .try_add(exp_neg_m)?
+            .try_broadcast_like(&shape)?;
+        num.try_div(den)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_quiet_softmax_1d() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let r = x.leaky_trace().quiet_softmax();
+        // every entry is strictly less than the corresponding ordinary softmax
+        // entry, and the row sums to less than 1.
+        assert!(r.clone().sum::<Rank0, _>().array() < 1.0);
+        let g = r.sum().backward();
+        assert_ne!(g.get(&x).array(), [0.0; 5]);
+    }
+
+    #[test]
+    fn test_quiet_softmax_all_negative_row_attends_to_nothing() {
+        let dev: TestDevice = Default::default();
+        // when every logit is very negative, the virtual zero logit should
+        // absorb almost all of the probability mass.
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-20.0, -20.0, -20.0]);
+        let r = x.quiet_softmax();
+        assert_close(&r.sum::<Rank0, _>().array(), &0.0);
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{shapes::*, tensor::*};
+
+/// Selects which per-axis reduction [ReduceTo::try_reduce_like] performs.
+/// [SumKind], [MeanKind] and [MaxKind] each just forward to the
+/// `try_sum`/`try_mean`/`try_max` already used elsewhere in `tensor_ops`
+/// (see e.g. [super::quiet_softmax]) - this trait only picks *which* of
+/// those to call, it doesn't reimplement any of them.
+pub trait ReduceKind<Src: HasErr + HasShape, Dst: Shape, Ax: Axes> {
+    fn try_reduce(src: Src, dst: &Dst) -> Result<Src::WithShape<Dst>, Src::Err>
+    where
+        Src::Shape: ReduceShapeTo<Dst, Ax>;
+}
+
+/// Reduces via [Tensor::try_sum].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SumKind;
+
+/// Reduces via [Tensor::try_mean].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MeanKind;
+
+/// Reduces via [Tensor::try_max].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MaxKind;
+
+impl<S: Shape, Dst: Shape, Ax: Axes, E: Dtype, D: Device<E>, T: Tape<E, D>>
+    ReduceKind<Tensor<S, E, D, T>, Dst, Ax> for SumKind
+{
+    fn try_reduce(src: Tensor<S, E, D, T>, _dst: &Dst) -> Result<Tensor<Dst, E, D, T>, D::Err>
+    where
+        S: ReduceShapeTo<Dst, Ax>,
+    {
+        src.try_sum()
+    }
+}
+
+impl<S: Shape, Dst: Shape, Ax: Axes, E: Dtype, D: Device<E>, T: Tape<E, D>>
+    ReduceKind<Tensor<S, E, D, T>, Dst, Ax> for MeanKind
+{
+    fn try_reduce(src: Tensor<S, E, D, T>, _dst: &Dst) -> Result<Tensor<Dst, E, D, T>, D::Err>
+    where
+        S: ReduceShapeTo<Dst, Ax>,
+    {
+        src.try_mean()
+    }
+}
+
+impl<S: Shape, Dst: Shape, Ax: Axes, E: Dtype, D: Device<E>, T: Tape<E, D>>
+    ReduceKind<Tensor<S, E, D, T>, Dst, Ax> for MaxKind
+{
+    fn try_reduce(src: Tensor<S, E, D, T>, _dst: &Dst) -> Result<Tensor<Dst, E, D, T>, D::Err>
+    where
+        S: ReduceShapeTo<Dst, Ax>,
+    {
+        src.try_max()
+    }
+}
+
+/// Reduce self into a new shape. This is the mirror image of [super::BroadcastTo]:
+/// where that trait spreads a tensor's values out along new axes, this one removes
+/// axes by reducing over them, via a chosen [ReduceKind] (e.g. [SumKind]).
+///
+/// Every method below is sugar over [ReduceTo::try_reduce_like], closing the
+/// asymmetry where [super::BroadcastTo] has
+/// `broadcast_top_dims`/`broadcast_bottom_dims` but reductions did not.
+pub trait ReduceTo: HasErr + HasShape + Sized {
+    /// Reduce into shape `Dst` along axes `Ax`, given the destination shape,
+    /// using `K` (e.g. [SumKind]) to actually fold the axes away.
+    fn try_reduce_like<K: ReduceKind<Self, Dst, Ax>, Dst: Shape, Ax: Axes>(
+        self,
+        dst: &Dst,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        K::try_reduce(self, dst)
+    }
+
+    /// Same as [ReduceTo::try_reduce_like], but the axes to reduce are automatically
+    /// chosen to be the top axes of `self`.
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank3<3, 5, 7>, f32, _> = dev.zeros();
+    ///
+    /// // sums axis 0
+    /// let b: Tensor<Rank2<5, 7>, f32, _> = a.clone().reduce_top_dims::<SumKind, _>();
+    ///
+    /// // sums axes 0 and 1
+    /// let c: Tensor<Rank1<7>, f32, _> = a.reduce_top_dims::<SumKind, _>();
+    /// ```
+    fn reduce_top_dims<K, Dst: ConstShape>(self) -> Self::WithShape<Dst>
+    where
+        Self::Shape: ReduceTopDimsTo<Dst>,
+        K: ReduceKind<Self, Dst, <Self::Shape as ReduceTopDimsTo<Dst>>::Ax>,
+    {
+        self.try_reduce_top_dims::<K, Dst>().unwrap()
+    }
+    /// Fallible version of [ReduceTo::reduce_top_dims]
+    fn try_reduce_top_dims<K, Dst: ConstShape>(self) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReduceTopDimsTo<Dst>,
+        K: ReduceKind<Self, Dst, <Self::Shape as ReduceTopDimsTo<Dst>>::Ax>,
+    {
+        self.try_reduce_top_dims_like::<K, Dst>(&Default::default())
+    }
+    /// Same as [ReduceTo::reduce_top_dims], but the target shape is given
+    fn reduce_top_dims_like<K, Dst: Shape>(self, dst: &Dst) -> Self::WithShape<Dst>
+    where
+        Self::Shape: ReduceTopDimsTo<Dst>,
+        K: ReduceKind<Self, Dst, <Self::Shape as ReduceTopDimsTo<Dst>>::Ax>,
+    {
+        self.try_reduce_top_dims_like::<K, Dst>(dst).unwrap()
+    }
+    /// Fallible version of [ReduceTo::reduce_top_dims_like]
+    fn try_reduce_top_dims_like<K, Dst: Shape>(
+        self,
+        dst: &Dst,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReduceTopDimsTo<Dst>,
+        K: ReduceKind<Self, Dst, <Self::Shape as ReduceTopDimsTo<Dst>>::Ax>,
+    {
+        self.try_reduce_like::<K, Dst, <Self::Shape as ReduceTopDimsTo<Dst>>::Ax>(dst)
+    }
+
+    /// Same as [ReduceTo::try_reduce_like], but the axes to reduce are automatically
+    /// chosen to be the bottom axes of `self`.
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank3<3, 5, 7>, f32, _> = dev.zeros();
+    ///
+    /// // sums axis 2
+    /// let b: Tensor<Rank2<3, 5>, f32, _> = a.clone().reduce_bottom_dims::<SumKind, _>();
+    ///
+    /// // sums axes 1 and 2
+    /// let c: Tensor<Rank1<3>, f32, _> = a.reduce_bottom_dims::<SumKind, _>();
+    /// ```
+    fn reduce_bottom_dims<K, Dst: ConstShape>(self) -> Self::WithShape<Dst>
+    where
+        Self::Shape: ReduceBottomDimsTo<Dst>,
+        K: ReduceKind<Self, Dst, <Self::Shape as ReduceBottomDimsTo<Dst>>::Ax>,
+    {
+        self.try_reduce_bottom_dims::<K, Dst>().unwrap()
+    }
+    /// Fallible version of [ReduceTo::reduce_bottom_dims]
+    fn try_reduce_bottom_dims<K, Dst: ConstShape>(self) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReduceBottomDimsTo<Dst>,
+        K: ReduceKind<Self, Dst, <Self::Shape as ReduceBottomDimsTo<Dst>>::Ax>,
+    {
+        self.try_reduce_bottom_dims_like::<K, Dst>(&Default::default())
+    }
+    /// Same as [ReduceTo::reduce_bottom_dims], but the target shape is given
+    fn reduce_bottom_dims_like<K, Dst: Shape>(self, dst: &Dst) -> Self::WithShape<Dst>
+    where
+        Self::Shape: ReduceBottomDimsTo<Dst>,
+        K: ReduceKind<Self, Dst, <Self::Shape as ReduceBottomDimsTo<Dst>>::Ax>,
+    {
+        self.try_reduce_bottom_dims_like::<K, Dst>(dst).unwrap()
+    }
+    /// Fallible version of [ReduceTo::reduce_bottom_dims_like]
+    fn try_reduce_bottom_dims_like<K, Dst: Shape>(
+        self,
+        dst: &Dst,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReduceBottomDimsTo<Dst>,
+        K: ReduceKind<Self, Dst, <Self::Shape as ReduceBottomDimsTo<Dst>>::Ax>,
+    {
+        self.try_reduce_like::<K, Dst, <Self::Shape as ReduceBottomDimsTo<Dst>>::Ax>(dst)
+    }
+}
+
+impl<S: Shape, E: Dtype, D: DeviceStorage, T> ReduceTo for Tensor<S, E, D, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_reduce_top_dims() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 3>, TestDtype, _> =
+            dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b: Tensor<Rank1<3>, TestDtype, _> = a.clone().reduce_top_dims::<SumKind, _>();
+        assert_close(&b.array(), &[5.0, 7.0, 9.0]);
+        let c: Tensor<Rank1<3>, TestDtype, _> = a.reduce_top_dims::<MaxKind, _>();
+        assert_close(&c.array(), &[4.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn test_reduce_bottom_dims() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 3>, TestDtype, _> =
+            dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b: Tensor<Rank1<2>, TestDtype, _> = a.clone().reduce_bottom_dims::<SumKind, _>();
+        assert_close(&b.array(), &[6.0, 15.0]);
+        let c: Tensor<Rank1<2>, TestDtype, _> = a.reduce_bottom_dims::<MeanKind, _>();
+        assert_close(&c.array(), &[2.0, 5.0]);
+    }
+}
@@ -0,0 +1,159 @@
+//! A device backend that delegates unary/binary tensor ops to LibTorch via
+//! the `tch` crate: any op built on [UnaryKernel]/[BinaryKernel] (e.g.
+//! `leaky_relu`, `prelu`) dispatches to LibTorch's kernels once a
+//! `Tensor<S, E, Tch, T>` exists.
+//!
+//! This file only supplies that per-op forward/backward dispatch plus the
+//! minimal [DeviceStorage] every [UnaryKernel]/[BinaryKernel] impl requires -
+//! it does **not** yet give `Tch` a construction path (the
+//! `ZerosTensor`/`OnesTensor`/`SampleTensor`/`TensorFromVec`-equivalent impls
+//! `Cpu`/`Cuda` have), so there is currently no public way to build a
+//! `Tensor<S, E, Tch, T>` in the first place - wiring that up is left as
+//! follow-up work, not something this file claims to solve. Real allocation
+//! and shape/stride bookkeeping are still LibTorch's problem once that
+//! follow-up lands, not ours, since [TchStorage] just wraps a `tch::Tensor`
+//! that already tracks its own shape/strides/device.
+//!
+//! This also doesn't cover `mean` or `TransformerEncoderBlock`. The
+//! `Tensor0D`..`Tensor4D` `mean` and `TransformerEncoderBlock` predate the
+//! shape-generic `Device`/`Tensor<S, E, D, T>` system this file targets and
+//! aren't device-generic at all. The shape-generic `try_mean` that
+//! `MeanKind` in `reduce_to.rs` calls requires `D: Device<E>`, and `Tch`
+//! implements neither `Device` nor any reduction kernel.
+
+use super::ops::*;
+use crate::tensor::DeviceStorage;
+
+/// [DeviceStorage::Err] for [Tch]: none of this file's ops currently fail
+/// (LibTorch panics internally instead of returning a `Result`), but the
+/// associated type still has to be nameable.
+#[derive(Debug)]
+pub struct TchError;
+
+impl std::fmt::Display for TchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "a LibTorch op failed")
+    }
+}
+
+impl std::error::Error for TchError {}
+
+impl DeviceStorage for Tch {
+    type Storage = TchStorage;
+    type Err = TchError;
+}
+
+/// A device that dispatches tensor ops to LibTorch (via the `tch` crate)
+/// instead of dfdx's own Cpu/Cuda kernels.
+#[derive(Clone, Debug)]
+pub struct Tch {
+    pub(crate) device: tch::Device,
+}
+
+/// The storage backing a [Tch]-resident tensor: just the underlying
+/// `tch::Tensor`, since LibTorch already tracks its own shape/strides/device.
+#[derive(Clone, Debug)]
+pub struct TchStorage(pub(crate) tch::Tensor);
+
+impl Tch {
+    /// Wraps a raw `tch::Tensor` produced by a LibTorch op back into
+    /// [TchStorage].
+    fn wrap(&self, t: tch::Tensor) -> TchStorage {
+        TchStorage(t)
+    }
+
+    /// Borrows the raw `tch::Tensor` out of a [TchStorage] to pass into a
+    /// LibTorch op.
+    fn unwrap<'a>(&self, s: &'a TchStorage) -> &'a tch::Tensor {
+        &s.0
+    }
+
+    /// Accumulates `src` into `dst` in place, the same role
+    /// `cpu_kernels`/`cuda_kernels` fill with their own `add_assign`.
+    fn add_assign(&self, dst: &mut TchStorage, src: &tch::Tensor) {
+        dst.0 = (&dst.0) + src;
+    }
+}
+
+impl Default for Tch {
+    fn default() -> Self {
+        Self {
+            device: tch::Device::Cpu,
+        }
+    }
+}
+
+impl Tch {
+    pub fn cuda_if_available() -> Self {
+        Self {
+            device: tch::Device::cuda_if_available(),
+        }
+    }
+}
+
+/// Implements [UnaryKernel] for [Tch] given a forward and a derivative
+/// closure over a raw `tch::Tensor`, mirroring the shape of
+/// [cuda_unary!](crate::tensor_ops::cuda_kernels::cuda_unary) for the CUDA
+/// backend.
+macro_rules! tch_unary {
+    ($Op:ty, $TypeName:ty, $fwd:expr, $df:expr) => {
+        impl UnaryKernel<$Op, $TypeName> for Tch {
+            fn forward(&self, op: $Op, inp: &Self::Storage) -> Result<Self::Storage, Self::Err> {
+                let f: fn(&$Op, &tch::Tensor) -> tch::Tensor = $fwd;
+                Ok(self.wrap(f(&op, self.unwrap(inp))))
+            }
+
+            fn backward(
+                &self,
+                op: $Op,
+                inp: &Self::Storage,
+                grad_inp: &mut Self::Storage,
+                grad_out: &Self::Storage,
+            ) -> Result<(), Self::Err> {
+                let df: fn(&$Op, &tch::Tensor) -> tch::Tensor = $df;
+                let d = df(&op, self.unwrap(inp)) * self.unwrap(grad_out);
+                self.add_assign(grad_inp, &d);
+                Ok(())
+            }
+        }
+    };
+}
+
+/// Implements [BinaryKernel] for [Tch], mirroring
+/// [cuda_binary!](crate::tensor_ops::cuda_kernels::cuda_binary).
+macro_rules! tch_binary {
+    ($Op:ty, $TypeName:ty, $fwd:expr, $dfdx:expr, $dfdy:expr) => {
+        impl BinaryKernel<$Op, $TypeName> for Tch {
+            fn forward(
+                &self,
+                op: $Op,
+                lhs: &Self::Storage,
+                rhs: &Self::Storage,
+            ) -> Result<Self::Storage, Self::Err> {
+                let f: fn(&$Op, &tch::Tensor, &tch::Tensor) -> tch::Tensor = $fwd;
+                Ok(self.wrap(f(&op, self.unwrap(lhs), self.unwrap(rhs))))
+            }
+
+            fn backward(
+                &self,
+                op: $Op,
+                lhs: &Self::Storage,
+                grad_lhs: &mut Self::Storage,
+                rhs: &Self::Storage,
+                grad_rhs: &mut Self::Storage,
+                grad_out: &Self::Storage,
+            ) -> Result<(), Self::Err> {
+                let dfdx: fn(&$Op, &tch::Tensor, &tch::Tensor) -> tch::Tensor = $dfdx;
+                let dfdy: fn(&$Op, &tch::Tensor, &tch::Tensor) -> tch::Tensor = $dfdy;
+                let (l, r) = (self.unwrap(lhs), self.unwrap(rhs));
+                let go = self.unwrap(grad_out);
+                self.add_assign(grad_lhs, &(dfdx(&op, l, r) * go));
+                self.add_assign(grad_rhs, &(dfdy(&op, l, r) * go));
+                Ok(())
+            }
+        }
+    };
+}
+
+pub(crate) use tch_binary;
+pub(crate) use tch_unary;